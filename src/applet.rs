@@ -1,15 +1,20 @@
 use std::{
+    collections::{HashMap, VecDeque},
     fs,
-    path::Path,
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
 use nvml_wrapper::Nvml;
-use sysinfo::{Components, CpuRefreshKind, MemoryRefreshKind, Networks, RefreshKind, System};
+use starship_battery::{Manager as BatteryManager, State as BatteryState};
+use sysinfo::{
+    Components, CpuRefreshKind, MemoryRefreshKind, Networks, Pid, ProcessRefreshKind,
+    ProcessesToUpdate, RefreshKind, System,
+};
 use tracing::{debug, trace};
 
 use crate::{
-    config::{APP_ID, Flags, SysInfoConfig},
+    config::{APP_ID, Flags, ProcessSortKey, SysInfoConfig},
     fl,
 };
 
@@ -32,13 +37,126 @@ struct SysInfo {
     upload_speed: f64,
     last_scan: Instant,
     physical_interfaces: Vec<String>,
-    ups_temp: String,
-    // GPU monitoring (NVIDIA only via NVML)
-    nvml: Option<Nvml>,
+    ups_vars: HashMap<String, String>,
+    // GPU monitoring: NVML when available, DRM sysfs otherwise
+    gpu: Option<GpuBackend>,
     gpu_load: Option<u32>,
     gpu_temp: Option<u32>,
     gpu_vram_used: Option<u64>,
     gpu_vram_total: Option<u64>,
+    // Battery monitoring (laptops only; absent on desktops)
+    battery_manager: Option<BatteryManager>,
+    battery_percent: Option<f32>,
+    battery_state: Option<BatteryState>,
+    battery_secs_left: Option<u64>,
+    // Recent-history ring buffers, rendered as sparklines in the popup
+    cpu_history: VecDeque<f32>,
+    ram_history: VecDeque<f32>,
+    download_history: VecDeque<f32>,
+    upload_history: VecDeque<f32>,
+    // Every reported sensor label and temperature, not just the CPU pick
+    temp_sensors: Vec<(String, f32)>,
+    // Top `process_row_count` processes, refreshed once per tick while the
+    // popup is open, so `view_window` can render without re-sorting
+    top_processes: Vec<(Pid, String, f32, u64)>,
+}
+
+/// GPU harvesting backend: NVML covers NVIDIA, DRM sysfs covers everything
+/// else (AMD, Intel, Apple silicon) that exposes `gpu_busy_percent` and
+/// `mem_info_vram_*` under `/sys/class/drm/card*/device`. The DRM device
+/// path is re-resolved from `config.gpu_index` on every refresh (see
+/// `find_drm_gpu`), so it stays in sync if the user changes which card to
+/// display, the same as the NVML path re-reading `device_by_index`.
+enum GpuBackend {
+    Nvml(Nvml),
+    Drm,
+}
+
+/// Finds the `device` directory for the `index`-th DRM card (sorted by
+/// name), skipping connector aliases like `card0-DP-1`.
+fn find_drm_gpu(index: u32) -> Option<PathBuf> {
+    let mut cards: Vec<PathBuf> = fs::read_dir("/sys/class/drm")
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let suffix = name.strip_prefix("card")?;
+            suffix
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| entry.path().join("device"))
+        })
+        .filter(|device| device.exists())
+        .collect();
+
+    cards.sort();
+    cards.into_iter().nth(index as usize)
+}
+
+/// Reads GPU load, temperature, and VRAM usage for a DRM `device` directory.
+/// Any value that can't be read or parsed is left as `None` rather than
+/// failing the whole read.
+fn read_drm_gpu_stats(device: &Path) -> (Option<u32>, Option<u32>, Option<u64>, Option<u64>) {
+    let load = fs::read_to_string(device.join("gpu_busy_percent"))
+        .ok()
+        .and_then(|s| s.trim().parse().ok());
+
+    let vram_used = fs::read_to_string(device.join("mem_info_vram_used"))
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .map(|bytes| bytes / (1024 * 1024));
+
+    let vram_total = fs::read_to_string(device.join("mem_info_vram_total"))
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .map(|bytes| bytes / (1024 * 1024));
+
+    (load, find_drm_gpu_temp(device), vram_used, vram_total)
+}
+
+/// Finds the GPU temperature under `device/hwmon/hwmon*`, preferring the
+/// `junction` sensor over `edge` when both are present (matches how `edge`
+/// and `junction` are reported on AMDGPU).
+fn find_drm_gpu_temp(device: &Path) -> Option<u32> {
+    let mut best: Option<(bool, u32)> = None;
+
+    for hwmon_entry in fs::read_dir(device.join("hwmon")).ok()?.flatten() {
+        let hwmon_path = hwmon_entry.path();
+        let Ok(sensor_entries) = fs::read_dir(&hwmon_path) else {
+            continue;
+        };
+
+        for sensor_entry in sensor_entries.flatten() {
+            let name = sensor_entry.file_name().into_string().unwrap_or_default();
+            let Some(index) = name
+                .strip_prefix("temp")
+                .and_then(|s| s.strip_suffix("_input"))
+            else {
+                continue;
+            };
+
+            let label = fs::read_to_string(hwmon_path.join(format!("temp{index}_label")))
+                .unwrap_or_default();
+            let label = label.trim();
+            if label != "edge" && label != "junction" {
+                continue;
+            }
+
+            let Some(millidegrees) = fs::read_to_string(sensor_entry.path())
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            let is_junction = label == "junction";
+            if best.is_none_or(|(best_is_junction, _)| is_junction && !best_is_junction) {
+                best = Some((is_junction, millidegrees));
+            }
+        }
+    }
+
+    best.map(|(_, millidegrees)| millidegrees / 1000)
 }
 
 impl SysInfo {
@@ -71,73 +189,231 @@ impl SysInfo {
         self.last_scan = Instant::now();
     }
 
+    /// Pushes `value` onto a history ring buffer, trimming the front until
+    /// it holds at most `cap` samples.
+    fn push_history(buffer: &mut VecDeque<f32>, value: f32, cap: usize) {
+        buffer.push_back(value);
+        while buffer.len() > cap {
+            buffer.pop_front();
+        }
+    }
+
+    /// Reads charge, charge/discharge state, and estimated time remaining
+    /// from the first reported battery. Machines with no battery (desktops)
+    /// or a manager that failed to initialize leave all fields `None`.
+    fn update_battery_status(&mut self) {
+        let battery = self
+            .battery_manager
+            .as_ref()
+            .and_then(|manager| manager.batteries().ok())
+            .and_then(|mut batteries| batteries.next())
+            .and_then(|battery| battery.ok());
+
+        let Some(battery) = battery else {
+            self.battery_percent = None;
+            self.battery_state = None;
+            self.battery_secs_left = None;
+            return;
+        };
+
+        self.battery_percent = Some(battery.state_of_charge().value * 100.0);
+        self.battery_state = Some(battery.state());
+        self.battery_secs_left = match battery.state() {
+            BatteryState::Charging => battery.time_to_full().map(|t| t.value as u64),
+            BatteryState::Discharging => battery.time_to_empty().map(|t| t.value as u64),
+            _ => None,
+        };
+    }
+
     fn update_sysinfo_data(&mut self) {
         // Rescan interfaces every 10 seconds
         if self.last_scan.elapsed() > Duration::from_secs(10) {
             self.rescan_physical_interfaces();
         }
 
-        self.system.refresh_specifics(
-            RefreshKind::nothing()
-                .with_memory(MemoryRefreshKind::nothing().with_ram())
-                .with_cpu(CpuRefreshKind::nothing().with_cpu_usage()),
-        );
+        if self.config.show_cpu || self.config.show_ram {
+            let memory_kind = if self.config.show_ram {
+                if self.config.include_swap_in_ram {
+                    MemoryRefreshKind::nothing().with_ram().with_swap()
+                } else {
+                    MemoryRefreshKind::nothing().with_ram()
+                }
+            } else {
+                MemoryRefreshKind::nothing()
+            };
+            let cpu_kind = if self.config.show_cpu {
+                CpuRefreshKind::nothing().with_cpu_usage()
+            } else {
+                CpuRefreshKind::nothing()
+            };
+
+            self.system.refresh_specifics(
+                RefreshKind::nothing()
+                    .with_memory(memory_kind)
+                    .with_cpu(cpu_kind),
+            );
+        }
 
-        self.cpu_usage = self.system.global_cpu_usage();
-        self.ram_usage = if self.config.include_swap_in_ram {
-            ((self.system.used_memory() + self.system.used_swap()) * 100)
-                / (self.system.total_memory() + self.system.total_swap())
-        } else {
-            (self.system.used_memory() * 100) / self.system.total_memory()
-        };
+        if self.config.show_cpu {
+            self.cpu_usage = self.system.global_cpu_usage();
+            Self::push_history(
+                &mut self.cpu_history,
+                self.cpu_usage,
+                self.config.history_length,
+            );
+        }
 
-        // Refresh CPU temperature from components
-        // Look for common CPU temperature sensor labels: k10temp (AMD), coretemp (Intel), or "cpu"
-        self.components.refresh(true);
-        self.cpu_temp = self
-            .components
-            .iter()
-            .find(|c| {
-                let label = c.label().to_lowercase();
-                label.contains("k10temp")
-                    || label.contains("coretemp")
-                    || label.contains("cpu")
-                    || label.contains("tctl") // AMD Ryzen Tctl
-            })
-            .and_then(|c| c.temperature());
+        if self.config.show_ram {
+            self.ram_usage = if self.config.include_swap_in_ram {
+                ((self.system.used_memory() + self.system.used_swap()) * 100)
+                    / (self.system.total_memory() + self.system.total_swap())
+            } else {
+                (self.system.used_memory() * 100) / self.system.total_memory()
+            };
+            Self::push_history(
+                &mut self.ram_history,
+                self.ram_usage as f32,
+                self.config.history_length,
+            );
+        }
 
-        self.networks.refresh(true);
+        if self.config.show_temps {
+            self.components.refresh(true);
+
+            self.temp_sensors = self
+                .components
+                .iter()
+                .map(|c| (c.label().to_string(), c.temperature().unwrap_or(f32::NAN)))
+                .collect();
+
+            self.cpu_temp = match &self.config.cpu_temp_sensor_label {
+                // User picked a specific sensor label to drive the compact readout
+                Some(label) => self
+                    .temp_sensors
+                    .iter()
+                    .find(|(sensor_label, _)| sensor_label == label)
+                    .map(|(_, temp)| *temp)
+                    .filter(|temp| !temp.is_nan()),
+                // Default heuristic: common CPU temperature sensor labels
+                // (k10temp for AMD, coretemp for Intel, or "cpu"/"tctl")
+                None => self
+                    .components
+                    .iter()
+                    .find(|c| {
+                        let label = c.label().to_lowercase();
+                        label.contains("k10temp")
+                            || label.contains("coretemp")
+                            || label.contains("cpu")
+                            || label.contains("tctl") // AMD Ryzen Tctl
+                    })
+                    .and_then(|c| c.temperature()),
+            };
+        }
+
+        if self.config.show_network {
+            self.networks.refresh(true);
 
-        let mut upload = 0;
-        let mut download = 0;
+            let mut upload = 0;
+            let mut download = 0;
 
-        for (name, data) in self.networks.iter() {
-            if self.physical_interfaces.contains(name) {
-                upload += data.transmitted();
-                download += data.received();
+            for (name, data) in self.networks.iter() {
+                if self.physical_interfaces.contains(name) {
+                    upload += data.transmitted();
+                    download += data.received();
+                }
             }
+
+            self.upload_speed = (upload as f64) / 1_000_000.0;
+            self.download_speed = (download as f64) / 1_000_000.0;
+            Self::push_history(
+                &mut self.download_history,
+                self.download_speed as f32,
+                self.config.history_length,
+            );
+            Self::push_history(
+                &mut self.upload_history,
+                self.upload_speed as f32,
+                self.config.history_length,
+            );
+        }
+
+        if self.config.show_ups {
+            self.ups_vars = get_ups_vars(&self.config);
         }
 
-        self.upload_speed = (upload as f64) / 1_000_000.0;
-        self.download_speed = (download as f64) / 1_000_000.0;
-        self.ups_temp = get_ups_temp();
-
-        // Update GPU stats from NVML (NVIDIA only)
-        if let Some(ref nvml) = self.nvml {
-            if let Ok(device) = nvml.device_by_index(0) {
-                // GPU utilization (load)
-                self.gpu_load = device.utilization_rates().ok().map(|u| u.gpu);
-                // GPU temperature
-                self.gpu_temp = device
-                    .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
-                    .ok();
-                // GPU VRAM
-                if let Ok(mem_info) = device.memory_info() {
-                    self.gpu_vram_used = Some(mem_info.used / (1024 * 1024)); // Convert to MB
-                    self.gpu_vram_total = Some(mem_info.total / (1024 * 1024)); // Convert to MB
+        if self.config.show_gpu {
+            // Update GPU stats via whichever backend was available at init
+            match &self.gpu {
+                Some(GpuBackend::Nvml(nvml)) => {
+                    if let Ok(device) = nvml.device_by_index(self.config.gpu_index) {
+                        // GPU utilization (load)
+                        self.gpu_load = device.utilization_rates().ok().map(|u| u.gpu);
+                        // GPU temperature
+                        self.gpu_temp = device
+                            .temperature(
+                                nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu,
+                            )
+                            .ok();
+                        // GPU VRAM
+                        if let Ok(mem_info) = device.memory_info() {
+                            self.gpu_vram_used = Some(mem_info.used / (1024 * 1024)); // Convert to MB
+                            self.gpu_vram_total = Some(mem_info.total / (1024 * 1024)); // Convert to MB
+                        }
+                    }
                 }
+                Some(GpuBackend::Drm) => {
+                    if let Some(device) = find_drm_gpu(self.config.gpu_index) {
+                        (
+                            self.gpu_load,
+                            self.gpu_temp,
+                            self.gpu_vram_used,
+                            self.gpu_vram_total,
+                        ) = read_drm_gpu_stats(&device);
+                    }
+                }
+                None => {}
             }
         }
+
+        if self.config.show_battery {
+            self.update_battery_status();
+        }
+
+        // Process enumeration is comparatively expensive, so only do it
+        // while the popup showing the process list is actually open.
+        if self.popup.is_some() {
+            self.system.refresh_processes_specifics(
+                ProcessesToUpdate::All,
+                true,
+                ProcessRefreshKind::nothing().with_cpu().with_memory(),
+            );
+            self.top_processes = self.top_processes();
+        }
+    }
+
+    /// Top `process_row_count` processes, sorted by `process_sort_key`.
+    fn top_processes(&self) -> Vec<(Pid, String, f32, u64)> {
+        let mut processes: Vec<(Pid, String, f32, u64)> = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, process)| {
+                (
+                    *pid,
+                    process.name().to_string_lossy().into_owned(),
+                    process.cpu_usage(),
+                    process.memory(),
+                )
+            })
+            .collect();
+
+        match self.config.process_sort_key {
+            ProcessSortKey::Cpu => processes.sort_by(|a, b| b.2.total_cmp(&a.2)),
+            ProcessSortKey::Memory => processes.sort_by(|a, b| b.3.cmp(&a.3)),
+        }
+
+        processes.truncate(self.config.process_row_count);
+        processes
     }
 }
 
@@ -178,8 +454,14 @@ impl cosmic::Application for SysInfo {
         let last_scan = Instant::now();
         let physical_interfaces = SysInfo::get_physical_interfaces(&config);
 
-        // Initialize NVML for NVIDIA GPU monitoring (may fail on non-NVIDIA systems)
-        let nvml = Nvml::init().ok();
+        // Try NVML first (NVIDIA), then fall back to DRM sysfs (AMD, Intel, Apple silicon)
+        let gpu = Nvml::init()
+            .ok()
+            .map(GpuBackend::Nvml)
+            .or_else(|| find_drm_gpu(config.gpu_index).map(|_| GpuBackend::Drm));
+
+        // May fail to initialize on machines with no power supply subsystem
+        let battery_manager = BatteryManager::new().ok();
 
         (
             Self {
@@ -197,12 +479,22 @@ impl cosmic::Application for SysInfo {
                 upload_speed: 0.00,
                 last_scan,
                 physical_interfaces,
-                ups_temp: String::from("..."),
-                nvml,
+                ups_vars: HashMap::new(),
+                gpu,
                 gpu_load: None,
                 gpu_temp: None,
                 gpu_vram_used: None,
                 gpu_vram_total: None,
+                battery_manager,
+                battery_percent: None,
+                battery_state: None,
+                battery_secs_left: None,
+                cpu_history: VecDeque::new(),
+                ram_history: VecDeque::new(),
+                download_history: VecDeque::new(),
+                upload_history: VecDeque::new(),
+                temp_sensors: Vec::new(),
+                top_processes: Vec::new(),
             },
             cosmic::task::none(),
         )
@@ -279,50 +571,96 @@ impl cosmic::Application for SysInfo {
     }
 
     fn view(&self) -> cosmic::Element<'_, Message> {
-        // Format CPU temp (show N/A if unavailable)
-        let cpu_temp_str = self
-            .cpu_temp
-            .map(|t| format!("{:.0}°C", t))
-            .unwrap_or_else(|| "N/A".to_string());
-
-        // Format GPU stats
-        let gpu_display = match (
-            self.gpu_load,
-            self.gpu_temp,
-            self.gpu_vram_used,
-            self.gpu_vram_total,
-        ) {
-            (Some(load), Some(temp), Some(used), Some(total)) => {
-                format!(
-                    "GPU {}% {}°C {:.1}/{:.1}GB",
-                    load,
-                    temp,
-                    used as f64 / 1024.0,
-                    total as f64 / 1024.0
-                )
-            }
-            _ => "GPU N/A".to_string(),
-        };
+        let mut segments: Vec<cosmic::Element<'_, Message>> = Vec::new();
+
+        if self.config.show_cpu {
+            let label = if self.config.show_temps {
+                let cpu_temp_str = self
+                    .cpu_temp
+                    .map(|t| format!("{:.0}°C", t))
+                    .unwrap_or_else(|| "N/A".to_string());
+                format!("CPU {:.0}% {}", self.cpu_usage, cpu_temp_str)
+            } else {
+                format!("CPU {:.0}%", self.cpu_usage)
+            };
+            segments.push(cosmic::iced_widget::text(label).into());
+        }
 
-        let data = {
-            cosmic::iced_widget::row![
-                cosmic::iced_widget::text(format!("CPU {:.0}% {}", self.cpu_usage, cpu_temp_str)),
-                cosmic::iced_widget::text("|"),
-                cosmic::iced_widget::text(format!("RAM {}%", self.ram_usage)),
-                cosmic::iced_widget::text("|"),
-                cosmic::iced_widget::text(format!("UPS {}°C", self.ups_temp)),
-                cosmic::iced_widget::text("|"),
-                cosmic::iced_widget::text(gpu_display),
-                cosmic::iced_widget::text("|"),
+        if self.config.show_ram {
+            segments.push(cosmic::iced_widget::text(format!("RAM {}%", self.ram_usage)).into());
+        }
+
+        if self.config.show_ups {
+            let ups_display = self
+                .config
+                .ups_display_vars
+                .iter()
+                .map(|key| {
+                    let value = self.ups_vars.get(key).map(String::as_str).unwrap_or("N/A");
+                    format!("{} {}", ups_var_label(key), value)
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            segments.push(cosmic::iced_widget::text(format!("UPS {}", ups_display)).into());
+        }
+
+        if self.config.show_gpu {
+            let gpu_display = match (
+                self.gpu_load,
+                self.gpu_temp,
+                self.gpu_vram_used,
+                self.gpu_vram_total,
+            ) {
+                (Some(load), Some(temp), Some(used), Some(total)) => {
+                    format!(
+                        "GPU {}% {}°C {:.1}/{:.1}GB",
+                        load,
+                        temp,
+                        used as f64 / 1024.0,
+                        total as f64 / 1024.0
+                    )
+                }
+                _ => "GPU N/A".to_string(),
+            };
+            segments.push(cosmic::iced_widget::text(gpu_display).into());
+        }
+
+        if self.config.show_battery
+            && let Some(percent) = self.battery_percent
+        {
+            let arrow = match self.battery_state {
+                Some(BatteryState::Charging) => "↑",
+                Some(BatteryState::Discharging) => "↓",
+                _ => "",
+            };
+            let time_left = self
+                .battery_secs_left
+                .map(|secs| format!("{}{}:{:02}", arrow, secs / 60, secs % 60))
+                .unwrap_or_default();
+            segments.push(
+                cosmic::iced_widget::text(format!("BAT {:.0}% {}", percent, time_left)).into(),
+            );
+        }
+
+        if self.config.show_network {
+            segments.push(
                 cosmic::iced_widget::text(format!(
                     "↓{:.2}M/s ↑{:.2}M/s",
                     self.download_speed, self.upload_speed
-                )),
-            ]
-            .spacing(4)
-        };
+                ))
+                .into(),
+            );
+        }
+
+        let mut row = cosmic::iced_widget::row![].spacing(4);
+        for (index, segment) in segments.into_iter().enumerate() {
+            if index > 0 {
+                row = row.push(cosmic::iced_widget::text("|"));
+            }
+            row = row.push(segment);
+        }
 
-        let button = cosmic::widget::button::custom(data)
+        let button = cosmic::widget::button::custom(row)
             .class(cosmic::theme::Button::AppletIcon)
             .on_press_down(Message::ToggleWindow);
 
@@ -337,11 +675,63 @@ impl cosmic::Application for SysInfo {
                 .on_toggle(Message::ToggleIncludeSwapWithRam),
         ];
 
-        let data = cosmic::iced_widget::column![
+        let mut history = cosmic::iced_widget::column![].spacing(4);
+        if self.config.show_cpu {
+            history = history.push(cosmic::widget::text(format!(
+                "CPU  {}",
+                sparkline(&self.cpu_history, 100.0)
+            )));
+        }
+        if self.config.show_ram {
+            history = history.push(cosmic::widget::text(format!(
+                "RAM  {}",
+                sparkline(&self.ram_history, 100.0)
+            )));
+        }
+        if self.config.show_network {
+            history = history.push(cosmic::widget::text(format!(
+                "DOWN {}",
+                sparkline(&self.download_history, history_max(&self.download_history))
+            )));
+            history = history.push(cosmic::widget::text(format!(
+                "UP   {}",
+                sparkline(&self.upload_history, history_max(&self.upload_history))
+            )));
+        }
+
+        let mut processes = cosmic::iced_widget::column![].spacing(2);
+        for (pid, name, cpu, memory) in &self.top_processes {
+            processes = processes.push(cosmic::widget::text(format!(
+                "{:<20} {:>6} {:>5.1}% {:>6}MB",
+                name,
+                pid,
+                cpu,
+                memory / (1024 * 1024)
+            )));
+        }
+
+        let mut data = cosmic::iced_widget::column![
             // padding comment to make formatting nicer
-            cosmic::applet::padded_control(include_swap_in_ram_toggler)
-        ]
-        .padding([16, 0]);
+            cosmic::applet::padded_control(include_swap_in_ram_toggler),
+            cosmic::applet::padded_control(history),
+            cosmic::applet::padded_control(processes),
+        ];
+        if self.config.show_temps {
+            let mut temp_sensors = cosmic::iced_widget::column![].spacing(2);
+            for (label, temp) in &self.temp_sensors {
+                let reading = if temp.is_nan() {
+                    "N/A".to_string()
+                } else {
+                    format!("{:.0}°C", temp)
+                };
+                temp_sensors =
+                    temp_sensors.push(cosmic::widget::text(format!("{:<24} {}", label, reading)));
+            }
+            let temp_sensors =
+                cosmic::widget::scrollable(temp_sensors).height(cosmic::iced::Length::Fixed(120.0));
+            data = data.push(cosmic::applet::padded_control(temp_sensors));
+        }
+        let data = data.padding([16, 0]);
 
         self.core
             .applet
@@ -350,18 +740,83 @@ impl cosmic::Application for SysInfo {
     }
 }
 
-fn get_ups_temp() -> String {
-    let output = std::process::Command::new("upsc")
-        .arg("eaton@localhost")
-        .output();
+/// Renders `history` as a compact Unicode block-character sparkline, scaling
+/// each sample against `max`.
+fn sparkline(history: &VecDeque<f32>, max: f32) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    history
+        .iter()
+        .map(|value| {
+            let ratio = if max > 0.0 {
+                (value / max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let index = (ratio * (BLOCKS.len() - 1) as f32).round() as usize;
+            BLOCKS[index.min(BLOCKS.len() - 1)]
+        })
+        .collect()
+}
 
-    if let Ok(out) = output {
-        let stdout = String::from_utf8_lossy(&out.stdout);
-        for line in stdout.lines() {
-            if line.contains("ups.temperature") {
-                return line.split(':').nth(1).unwrap_or("N/A").trim().to_string();
-            }
-        }
+/// Highest sample in `history`, used as the sparkline scale for metrics
+/// (like network speed) with no fixed upper bound.
+fn history_max(history: &VecDeque<f32>) -> f32 {
+    history.iter().cloned().fold(0.0, f32::max)
+}
+
+/// Short key/unit label shown before a `upsc` variable's value in the panel,
+/// so the UPS segment stays legible once more than one var is configured.
+/// Falls back to the raw `upsc` key for anything not called out here.
+fn ups_var_label(key: &str) -> &str {
+    match key {
+        "ups.temperature" | "battery.temperature" => "temp",
+        "battery.charge" => "chg%",
+        "battery.runtime" => "rt",
+        "ups.load" => "load%",
+        "ups.status" => "status",
+        other => other,
+    }
+}
+
+/// Runs `upsc <ups_name>@<ups_host>` and parses its `key: value` output into
+/// a map. Returns all configured display vars set to "N/A" if `upsc` is
+/// missing, fails to run, or exits non-zero (e.g. no UPS configured).
+fn get_ups_vars(config: &SysInfoConfig) -> HashMap<String, String> {
+    let target = format!("{}@{}", config.ups_name, config.ups_host);
+    let output = std::process::Command::new("upsc").arg(&target).output();
+
+    let na = || {
+        config
+            .ups_display_vars
+            .iter()
+            .map(|key| (key.clone(), "N/A".to_string()))
+            .collect()
+    };
+
+    let Ok(output) = output else {
+        return na();
+    };
+    if !output.status.success() {
+        return na();
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut vars: HashMap<String, String> = stdout
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    if let Some(runtime_secs) = vars
+        .get("battery.runtime")
+        .and_then(|secs| secs.parse::<u64>().ok())
+    {
+        vars.insert(
+            "battery.runtime".to_string(),
+            format!("{:02}:{:02}", runtime_secs / 60, runtime_secs % 60),
+        );
     }
-    "N/A".to_string()
+
+    vars
 }
@@ -1,17 +1,85 @@
 use cosmic::cosmic_config::{
     self, Config, CosmicConfigEntry, cosmic_config_derive::CosmicConfigEntry,
 };
+use serde::{Deserialize, Serialize};
 
 const CONFIG_VERSION: u64 = 1;
 
+/// Which metric the popup's process list is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum ProcessSortKey {
+    #[default]
+    Cpu,
+    Memory,
+}
+
 pub(crate) const APP_ID: &str = "com.github.citizenzer0.CosmicUpsMonitor";
 
-#[derive(Default, Debug, Clone, CosmicConfigEntry)]
+#[derive(Debug, Clone, CosmicConfigEntry)]
 pub(crate) struct SysInfoConfig {
     pub(crate) include_interfaces: Option<Vec<String>>,
     pub(crate) exclude_interfaces: Option<Vec<String>>,
     /// Whether to include Swap usage in the RAM segment
     pub(crate) include_swap_in_ram: bool,
+    /// Which GPU to report on when more than one is present. Indexes NVML
+    /// devices when NVML is available, or `/sys/class/drm/card*` entries
+    /// (sorted) when falling back to the DRM sysfs backend. Re-selected on
+    /// every refresh regardless of backend.
+    pub(crate) gpu_index: u32,
+    /// NUT UPS name, as in the `ups` part of `upsc ups@host`
+    pub(crate) ups_name: String,
+    /// NUT server host, as in the `host` part of `upsc ups@host`
+    pub(crate) ups_host: String,
+    /// Which `upsc` variables to render in the panel, in order
+    pub(crate) ups_display_vars: Vec<String>,
+    /// Whether to show and refresh the CPU usage segment
+    pub(crate) show_cpu: bool,
+    /// Whether to show and refresh the RAM usage segment
+    pub(crate) show_ram: bool,
+    /// Whether to show and refresh the GPU segment
+    pub(crate) show_gpu: bool,
+    /// Whether to show and refresh the network speed segment
+    pub(crate) show_network: bool,
+    /// Whether to show and refresh the UPS segment
+    pub(crate) show_ups: bool,
+    /// Whether to show and refresh CPU temperature readings
+    pub(crate) show_temps: bool,
+    /// Whether to show and refresh the battery segment (desktops have no battery)
+    pub(crate) show_battery: bool,
+    /// Number of recent samples kept per metric for the popup sparklines
+    pub(crate) history_length: usize,
+    /// Which metric sorts the popup's process list
+    pub(crate) process_sort_key: ProcessSortKey,
+    /// How many rows the popup's process list shows
+    pub(crate) process_row_count: usize,
+    /// Which temperature sensor label drives the compact panel readout.
+    /// Falls back to the k10temp/coretemp/cpu/tctl heuristic when unset.
+    pub(crate) cpu_temp_sensor_label: Option<String>,
+}
+
+impl Default for SysInfoConfig {
+    fn default() -> Self {
+        Self {
+            include_interfaces: None,
+            exclude_interfaces: None,
+            include_swap_in_ram: false,
+            gpu_index: 0,
+            ups_name: String::from("ups"),
+            ups_host: String::from("localhost"),
+            ups_display_vars: vec![String::from("ups.temperature")],
+            show_cpu: true,
+            show_ram: true,
+            show_gpu: true,
+            show_network: true,
+            show_ups: true,
+            show_temps: true,
+            show_battery: true,
+            history_length: 60,
+            process_sort_key: ProcessSortKey::Cpu,
+            process_row_count: 5,
+            cpu_temp_sensor_label: None,
+        }
+    }
 }
 
 impl SysInfoConfig {